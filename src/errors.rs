@@ -1,6 +1,8 @@
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use core::ops::Bound;
+
+#[cfg(feature = "std")]
 use std::error::Error;
-use std::fmt::{Display, Formatter, Result as FmtResult};
-use std::ops::Bound;
 
 /// An error in calculating a partial sum.
 #[derive(Debug, PartialEq, Eq)]
@@ -10,6 +12,12 @@ pub enum SumError {
         bounds: (Bound<usize>, Bound<usize>),
         len: usize,
     },
+    // A rectangle is not within the bounds of the two-dimensional tree.
+    OutOfRange2D {
+        rows: (Bound<usize>, Bound<usize>),
+        cols: (Bound<usize>, Bound<usize>),
+        dims: (usize, usize),
+    },
 }
 
 /// An error in adding a delta to a tree element.
@@ -17,6 +25,11 @@ pub enum SumError {
 pub enum AddError {
     /// Index is greater than the size of the tree.
     IndexOutOfRange { index: usize, size: usize },
+    /// A `(row, col)` point lies outside the bounds of the two-dimensional tree.
+    IndexOutOfRange2D {
+        index: (usize, usize),
+        dims: (usize, usize),
+    },
 }
 
 impl Display for SumError {
@@ -25,6 +38,11 @@ impl Display for SumError {
             SumError::OutOfRange { bounds, len } => {
                 write!(f, "Bounds {:#?} are not in range (0..{})", bounds, len)
             }
+            SumError::OutOfRange2D { rows, cols, dims } => write!(
+                f,
+                "Rectangle (rows {:#?}, cols {:#?}) is not in range (0..{}, 0..{})",
+                rows, cols, dims.0, dims.1
+            ),
         }
     }
 }
@@ -35,10 +53,17 @@ impl Display for AddError {
             AddError::IndexOutOfRange { index, size } => {
                 write!(f, "Index `{}` is greater than the size `{}`", index, size)
             }
+            AddError::IndexOutOfRange2D { index, dims } => write!(
+                f,
+                "Point `({}, {})` is out of the bounds `({}, {})`",
+                index.0, index.1, dims.0, dims.1
+            ),
         }
     }
 }
 
+#[cfg(feature = "std")]
 impl Error for SumError {}
 
+#[cfg(feature = "std")]
 impl Error for AddError {}
@@ -136,11 +136,23 @@
 //! # References
 //! * [A New Data Structure for Cumulative Frequency Tables (1994)](https://citeseerx.ist.psu.edu/viewdoc/summary?doi=10.1.1.14.8917)
 
+#![no_std]
+
+#[macro_use]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+extern crate std;
+
 #[cfg(test)]
 mod tests;
 
 mod errors;
+mod range;
 mod tree;
+mod tree2d;
 
 pub use errors::{AddError, SumError};
+pub use range::RangeFenwickTree;
 pub use tree::FenwickTree;
+pub use tree2d::FenwickTree2D;
@@ -0,0 +1,118 @@
+use core::ops::{AddAssign, Bound, Mul, Sub, SubAssign};
+
+use num_traits::{NumCast, Zero};
+
+use crate::errors::{AddError, SumError};
+use crate::tree::FenwickTree;
+
+/// A Fenwick tree variant that supports range updates together with range queries, both in
+/// _O_(log _n_) time.
+///
+/// Unlike [`FenwickTree`], whose [`add`](FenwickTree::add) touches a single index, this type adds a
+/// `delta` to a whole inclusive range while still answering inclusive range sums. It is backed by
+/// the classic two-tree trick: a pair of inner [`FenwickTree`]s `b1` and `b2` that together encode
+/// the linear correction needed to turn point updates into range updates.
+pub struct RangeFenwickTree<I>
+where
+    I: Zero + Copy + AddAssign + SubAssign + Sub<Output = I> + Mul<Output = I> + NumCast,
+{
+    b1: FenwickTree<I>,
+    b2: FenwickTree<I>,
+    len: usize,
+}
+
+#[allow(clippy::len_without_is_empty)]
+impl<I> RangeFenwickTree<I>
+where
+    I: Zero + Copy + AddAssign + SubAssign + Sub<Output = I> + Mul<Output = I> + NumCast,
+{
+    /// Constructs a new range tree of the specified `len` with every element set as `I::zero()`.
+    pub fn with_len(len: usize) -> Self {
+        // One extra slot because positions are stored one-based (`1..=len`); index `0` stays unused.
+        Self {
+            b1: FenwickTree::with_len(len + 1),
+            b2: FenwickTree::with_len(len + 1),
+            len,
+        }
+    }
+
+    /// A number of elements covered by the tree.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Adds `delta` to every element in the inclusive range `[l, r]`.
+    ///
+    /// Complexity: _O_(log _n_).
+    pub fn add_range(&mut self, l: usize, r: usize, delta: I) -> Result<(), AddError> {
+        if r >= self.len {
+            return Err(AddError::IndexOutOfRange {
+                index: r,
+                size: self.len,
+            });
+        }
+
+        // Switch to the one-based positions the two-tree identity is stated in.
+        let l = l + 1;
+        let r = r + 1;
+
+        self.b1.add(l, delta)?;
+        self.b2.add(l, delta * cast(l - 1))?;
+
+        // `r + 1` is the virtual boundary; it only needs updating when it stays inside the tree.
+        if r < self.len {
+            self.b1.add(r + 1, neg(delta))?;
+            self.b2.add(r + 1, neg(delta * cast(r)))?;
+        }
+
+        Ok(())
+    }
+
+    /// A sum of the elements in the inclusive range `[l, r]`.
+    ///
+    /// Complexity: _O_(log _n_).
+    pub fn sum(&self, l: usize, r: usize) -> Result<I, SumError> {
+        if r >= self.len {
+            return Err(SumError::OutOfRange {
+                bounds: (Bound::Included(l), Bound::Included(r)),
+                len: self.len,
+            });
+        }
+
+        Ok(self.prefix(r + 1)? - self.prefix(l)?)
+    }
+
+    /// A prefix sum of the first `i` elements (one-based), i.e. the sum of the `[0, i)` half-open
+    /// range in zero-based terms.
+    fn prefix(&self, i: usize) -> Result<I, SumError> {
+        let b1 = self.b1.sum(..=i)?;
+        let b2 = self.b2.sum(..=i)?;
+
+        Ok(b1 * cast(i) - b2)
+    }
+}
+
+/// Casts a `usize` coordinate into the numeric type of the tree.
+///
+/// All positions fed here are tree indices, so the target type always fits them; the fallback of
+/// `I::zero()` keeps the helper total without widening the `NumCast` bound into a panic.
+#[inline(always)]
+fn cast<I>(value: usize) -> I
+where
+    I: Zero + NumCast,
+{
+    I::from(value).unwrap_or_else(I::zero)
+}
+
+/// Negates `delta` using only the `Zero`/`SubAssign` capabilities required by the tree, avoiding an
+/// extra `Neg` bound on `I`.
+#[inline(always)]
+fn neg<I>(delta: I) -> I
+where
+    I: Zero + SubAssign,
+{
+    let mut value = I::zero();
+
+    value -= delta;
+    value
+}
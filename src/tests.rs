@@ -1,4 +1,4 @@
-use std::ops::Bound;
+use core::ops::Bound;
 
 use crate::*;
 
@@ -10,6 +10,7 @@ fn sum_of_empty_range_is_0() {
 }
 
 #[test]
+#[allow(clippy::reversed_empty_ranges)]
 fn sum_of_decreasing_range_is_0() {
     let tree = new_tree(3);
 
@@ -116,6 +117,143 @@ fn range_sum_is_calculated_correctly_for_custom_bounds() {
     );
 }
 
+#[test]
+fn from_slice_builds_the_same_tree_as_add() {
+    let tree = FenwickTree::from_slice(&[1, 2, 3]);
+
+    assert_eq!(tree.sum(0..1).unwrap(), 1);
+    assert_eq!(tree.sum(0..2).unwrap(), 1 + 2);
+    assert_eq!(tree.sum(0..3).unwrap(), 1 + 2 + 3);
+    assert_eq!(tree.sum(1..3).unwrap(), 2 + 3);
+}
+
+#[test]
+fn from_vec_builds_the_same_tree_as_add() {
+    let tree = FenwickTree::from(vec![3, -1, 4, 1, 5]);
+
+    assert_eq!(tree.sum(..).unwrap(), 3 - 1 + 4 + 1 + 5);
+    assert_eq!(tree.sum(1..4).unwrap(), -1 + 4 + 1);
+}
+
+#[test]
+fn lower_bound_finds_first_prefix_reaching_target() {
+    // Counts `[1, 0, 2, 1]` with prefix sums `1, 1, 3, 4`.
+    let tree = FenwickTree::from(vec![1, 0, 2, 1]);
+
+    assert_eq!(tree.lower_bound(1), 0);
+    assert_eq!(tree.lower_bound(2), 2);
+    assert_eq!(tree.lower_bound(3), 2);
+    assert_eq!(tree.lower_bound(4), 3);
+}
+
+#[test]
+fn lower_bound_returns_len_when_target_is_unreachable() {
+    let tree = FenwickTree::from(vec![1, 1, 1]);
+
+    assert_eq!(tree.lower_bound(4), 3);
+}
+
+#[test]
+fn range_tree_add_range_is_reflected_in_range_sums() {
+    let mut tree = RangeFenwickTree::<i64>::with_len(5);
+
+    tree.add_range(1, 3, 2).unwrap();
+
+    assert_eq!(tree.sum(0, 4).unwrap(), 2 + 2 + 2);
+    assert_eq!(tree.sum(1, 3).unwrap(), 2 + 2 + 2);
+    assert_eq!(tree.sum(0, 0).unwrap(), 0);
+    assert_eq!(tree.sum(4, 4).unwrap(), 0);
+}
+
+#[test]
+fn range_tree_accumulates_overlapping_updates() {
+    let mut tree = RangeFenwickTree::<i64>::with_len(4);
+
+    tree.add_range(0, 3, 1).unwrap();
+    tree.add_range(1, 2, 5).unwrap();
+
+    assert_eq!(tree.sum(0, 3).unwrap(), 1 + 6 + 6 + 1);
+    assert_eq!(tree.sum(2, 3).unwrap(), 6 + 1);
+}
+
+#[test]
+fn range_tree_add_range_out_of_range_is_err() {
+    let mut tree = RangeFenwickTree::<i64>::with_len(3);
+
+    let err = tree.add_range(0, 3, 1).expect_err("");
+
+    assert_eq!(AddError::IndexOutOfRange { index: 3, size: 3 }, err);
+}
+
+#[test]
+fn tree_2d_rectangle_sum_is_calculated_correctly() {
+    // A 3x3 grid filled so that `(r, c)` holds `r * 3 + c + 1`.
+    let mut tree = FenwickTree2D::<i32>::with_dims(3, 3);
+
+    for r in 0..3 {
+        for c in 0..3 {
+            tree.add(r, c, (r * 3 + c + 1) as i32).unwrap();
+        }
+    }
+
+    assert_eq!(tree.sum(0..3, 0..3).unwrap(), (1..=9).sum());
+    assert_eq!(tree.sum(0..1, 0..3).unwrap(), 1 + 2 + 3);
+    assert_eq!(tree.sum(1..3, 1..3).unwrap(), 5 + 6 + 8 + 9);
+    assert_eq!(tree.sum(..=1, ..=1).unwrap(), 1 + 2 + 4 + 5);
+}
+
+#[test]
+fn tree_2d_sum_of_empty_rectangle_is_0() {
+    let tree = FenwickTree2D::<i32>::with_dims(3, 3);
+
+    let empty_rows = (Bound::Included(2), Bound::Excluded(2));
+
+    assert_eq!(tree.sum(empty_rows, 0..3).unwrap(), 0);
+}
+
+#[test]
+fn tree_2d_adding_at_invalid_point_is_err() {
+    let mut tree = FenwickTree2D::<i32>::with_dims(3, 3);
+
+    let err = tree.add(3, 0, 1).expect_err("");
+
+    assert_eq!(
+        AddError::IndexOutOfRange2D {
+            index: (3, 0),
+            dims: (3, 3)
+        },
+        err
+    );
+}
+
+#[test]
+fn get_returns_the_value_at_index() {
+    let tree = new_filled_tree(3);
+
+    assert_eq!(tree.get(0).unwrap(), 1);
+    assert_eq!(tree.get(1).unwrap(), 2);
+    assert_eq!(tree.get(2).unwrap(), 3);
+}
+
+#[test]
+fn set_overwrites_the_value_at_index() {
+    let mut tree = new_filled_tree(3);
+
+    tree.set(1, 10).unwrap();
+
+    assert_eq!(tree.get(1).unwrap(), 10);
+    assert_eq!(tree.sum(..).unwrap(), 1 + 10 + 3);
+}
+
+#[test]
+fn set_at_invalid_index_is_err() {
+    let mut tree = new_filled_tree(3);
+
+    let err = tree.set(3, 1).expect_err("");
+
+    assert_eq!(AddError::IndexOutOfRange { index: 3, size: 3 }, err);
+}
+
 fn new_filled_tree(size: usize) -> FenwickTree<i32> {
     let mut tree = new_tree(size);
 
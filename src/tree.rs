@@ -1,5 +1,6 @@
-use std::ops::{AddAssign, Bound, RangeBounds, SubAssign};
+use core::ops::{AddAssign, Bound, RangeBounds, SubAssign};
 
+use alloc::vec::Vec;
 use num_traits::Zero;
 
 use crate::errors::{AddError, SumError};
@@ -35,6 +36,28 @@ where
         }
     }
 
+    /// Constructs a new Fenwick tree from the values of `slice` in _O_(_n_) time.
+    ///
+    /// Building a tree by calling [`add`](FenwickTree::add) for each element costs _O_(_n_ log _n_);
+    /// when the data is known up front, this constructor propagates each partial range upward
+    /// exactly once, yielding the same layout in a single linear pass.
+    pub fn from_slice(slice: &[I]) -> Self {
+        let len = slice.len();
+        let mut tree = slice.to_vec();
+
+        for i in 0..len {
+            let parent = next(i);
+
+            if parent < len {
+                let child = tree[i];
+
+                tree[parent] += child;
+            }
+        }
+
+        Self { tree }
+    }
+
     /// A length of the backing vector of the tree.
     pub fn len(&self) -> usize {
         self.tree.len()
@@ -97,6 +120,80 @@ where
 
         Ok(())
     }
+
+    /// The current value stored at `i`.
+    ///
+    /// Complexity: _O_(log _n_).
+    ///
+    /// This is simply the single-element sum `sum(i..=i)`.
+    pub fn get(&self, i: usize) -> Result<I, SumError> {
+        self.sum(i..=i)
+    }
+
+    /// Overwrites the value at `i` with `value`.
+    ///
+    /// Complexity: _O_(log _n_).
+    ///
+    /// Implemented as `add(i, value - get(i))`, so the tree can be used as a mutable cumulative
+    /// array without the caller tracking element values externally.
+    pub fn set(&mut self, i: usize, value: I) -> Result<(), AddError> {
+        let size = self.len();
+
+        let mut delta = value;
+        delta -= self
+            .get(i)
+            .map_err(|_| AddError::IndexOutOfRange { index: i, size })?;
+
+        self.add(i, delta)
+    }
+}
+
+impl<I> FenwickTree<I>
+where
+    I: Zero + Copy + AddAssign + SubAssign + PartialOrd,
+{
+    /// Returns the smallest index `i` such that `sum(0..=i) >= target`.
+    ///
+    /// This turns a tree that holds non-negative increments into an order-statistic structure, e.g.
+    /// a count-Fenwick where `lower_bound` locates the _k_-th smallest element.
+    ///
+    /// Complexity: _O_(log _n_).
+    ///
+    /// The search is performed with binary lifting rather than an _O_(log² _n_) binary search: the
+    /// largest power of two `<= len` is halved down to `1`, descending into each range whose
+    /// cumulative sum is still below the remaining `target`. A returned index equal to
+    /// [`len`](FenwickTree::len) means no prefix reaches `target`.
+    pub fn lower_bound(&self, target: I) -> usize {
+        let len = self.len();
+
+        let mut pos = 0;
+        let mut remaining = target;
+
+        let mut step = 1;
+        while step << 1 <= len {
+            step <<= 1;
+        }
+
+        while step > 0 {
+            if pos + step <= len && self.tree[pos + step - 1] < remaining {
+                remaining -= self.tree[pos + step - 1];
+                pos += step;
+            }
+
+            step >>= 1;
+        }
+
+        pos
+    }
+}
+
+impl<I> From<Vec<I>> for FenwickTree<I>
+where
+    I: Zero + Copy + AddAssign + SubAssign,
+{
+    fn from(values: Vec<I>) -> Self {
+        Self::from_slice(&values)
+    }
 }
 
 /// Flips first trailing `1` in the binary representation of the `i`. Same as `i - (i & (-i))` (see
@@ -111,7 +208,7 @@ where
 /// because to iterate we need to call `i = prev(i) - 1`, which involves additional checks when `i`
 /// is of `usize` (decrement may result in panic).
 #[inline(always)]
-const fn prev(i: usize) -> usize {
+pub(crate) const fn prev(i: usize) -> usize {
     i & (i - 1)
 }
 
@@ -121,13 +218,13 @@ const fn prev(i: usize) -> usize {
 /// direction.
 /// However, unlike `prev`, this function assumes that indexing is zero-based, hence we access sums by `i`.
 #[inline(always)]
-const fn next(i: usize) -> usize {
+pub(crate) const fn next(i: usize) -> usize {
     i | (i + 1)
 }
 
 // As inclusive.
 #[inline(always)]
-fn start(bound: Bound<&usize>) -> usize {
+pub(crate) fn start(bound: Bound<&usize>) -> usize {
     match bound {
         Bound::Excluded(&usize::MAX) => usize::MAX,
         Bound::Excluded(x) => *x + 1,
@@ -138,7 +235,7 @@ fn start(bound: Bound<&usize>) -> usize {
 
 // As exclusive.
 #[inline(always)]
-fn end(bound: Bound<&usize>, len: usize) -> usize {
+pub(crate) fn end(bound: Bound<&usize>, len: usize) -> usize {
     match bound {
         Bound::Included(&usize::MAX) => usize::MAX,
         Bound::Included(x) => *x + 1,